@@ -1,6 +1,8 @@
 //! Array with SIMD alignment
 
+use std::alloc::{alloc, dealloc, Layout};
 use std::convert::TryInto;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::os::raw::c_void;
 use std::slice::{from_raw_parts, from_raw_parts_mut};
@@ -11,45 +13,118 @@ use crate::types::*;
 
 /// A RAII-wrapper of `fftw_alloc` and `fftw_free` with the [SIMD alignment].
 ///
+/// The backing store is provided by the [`Allocator`] `A`, defaulting to
+/// [`FftwAlloc`] so existing users keep FFTW's allocator and SIMD alignment.
+/// Downstream crates can substitute a pooling or arena allocator, or the
+/// FFTW-free [`GlobalAlignedAlloc`], without changing call sites.
+///
 /// [SIMD alignment]: http://www.fftw.org/fftw3_doc/SIMD-alignment-and-fftw_005fmalloc.html
 #[derive(Debug)]
-pub struct AlignedVec<T> {
+pub struct AlignedVec<T, A: Allocator = FftwAlloc> {
+    /// Number of initialized elements exposed through `Deref`/`len`.
     n: usize,
+    /// Number of elements the backing allocation can hold.
+    cap: usize,
     data: *mut T,
+    alloc: PhantomData<A>,
+}
+
+/// Backend providing the aligned storage for [`AlignedVec`].
+///
+/// Mirrors the shape of std's `Allocator`: a [`Layout`] in, a raw pointer out,
+/// and a matching `deallocate`. Implementors are zero-sized type-level tags, so
+/// the choice of allocator costs nothing at runtime.
+pub trait Allocator {
+    /// Alignment, in bytes, every allocation from this backend is aligned to.
+    const ALIGNMENT: usize;
+
+    /// Allocate the block described by `layout`.
+    ///
+    /// # Safety
+    /// `layout.size()` must be non-zero, mirroring [`std::alloc::alloc`].
+    unsafe fn allocate(layout: Layout) -> *mut u8;
+
+    /// Free a block previously returned by [`allocate`](Self::allocate).
+    ///
+    /// # Safety
+    /// `ptr` and `layout` must match a prior `allocate` call.
+    unsafe fn deallocate(ptr: *mut u8, layout: Layout);
+}
+
+/// Default backend wrapping `fftw_malloc`/`fftw_free`.
+///
+/// Preserves FFTW's [SIMD alignment] and the `FFTW_MUTEX` locking performed by
+/// the rest of the FFTW API (through the `excall!` macro).
+///
+/// [SIMD alignment]: http://www.fftw.org/fftw3_doc/SIMD-alignment-and-fftw_005fmalloc.html
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FftwAlloc;
+
+impl Allocator for FftwAlloc {
+    // FFTW's documented default SIMD alignment.
+    const ALIGNMENT: usize = 16;
+
+    unsafe fn allocate(layout: Layout) -> *mut u8 {
+        excall! { ffi::fftw_malloc(layout.size().try_into().unwrap()) as *mut u8 }
+    }
+
+    unsafe fn deallocate(ptr: *mut u8, _layout: Layout) {
+        excall! { ffi::fftw_free(ptr as *mut c_void) }
+    }
 }
 
-/// Allocate SIMD-aligned memory of Real/Complex type
+/// Backend over [`std::alloc`] guaranteeing `ALIGN`-byte alignment, without
+/// linking FFTW's allocator.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GlobalAlignedAlloc<const ALIGN: usize>;
+
+impl<const ALIGN: usize> Allocator for GlobalAlignedAlloc<ALIGN> {
+    const ALIGNMENT: usize = ALIGN;
+
+    unsafe fn allocate(layout: Layout) -> *mut u8 {
+        alloc(layout)
+    }
+
+    unsafe fn deallocate(ptr: *mut u8, layout: Layout) {
+        dealloc(ptr, layout)
+    }
+}
+
+/// Real/Complex scalar that can back an [`AlignedVec`].
+///
+/// Allocation is handled by the [`Allocator`] backend; this trait only carries
+/// the element-level invariants the container needs.
 pub trait AlignedAllocable: Zero + Clone + Copy + Sized {
-    /// Allocate SIMD-aligned memory
-    #[allow(clippy::missing_safety_doc)]
-    unsafe fn alloc(n: usize) -> *mut Self;
+    /// Size, in bytes, of the underlying real scalar (8 for `f64`/`c64`,
+    /// 4 for `f32`/`c32`). Used to byte-swap at scalar granularity when the
+    /// compact serde codec crosses endianness.
+    const SCALAR_BYTES: usize;
 }
 
 impl AlignedAllocable for f64 {
-    unsafe fn alloc(n: usize) -> *mut Self {
-        ffi::fftw_alloc_real(n.try_into().unwrap())
-    }
+    const SCALAR_BYTES: usize = 8;
 }
 
 impl AlignedAllocable for f32 {
-    unsafe fn alloc(n: usize) -> *mut Self {
-        ffi::fftwf_alloc_real(n.try_into().unwrap())
-    }
+    const SCALAR_BYTES: usize = 4;
 }
 
 impl AlignedAllocable for c64 {
-    unsafe fn alloc(n: usize) -> *mut Self {
-        ffi::fftw_alloc_complex(n.try_into().unwrap()) as *mut _
-    }
+    const SCALAR_BYTES: usize = 8;
 }
 
 impl AlignedAllocable for c32 {
-    unsafe fn alloc(n: usize) -> *mut Self {
-        ffi::fftwf_alloc_complex(n.try_into().unwrap()) as *mut c32
-    }
+    const SCALAR_BYTES: usize = 4;
 }
 
-impl<T> AlignedVec<T> {
+impl<T, A: Allocator> AlignedVec<T, A> {
+    /// `Layout` of the backing store for `count` elements under allocator `A`.
+    fn layout(count: usize) -> Layout {
+        let align = std::cmp::max(A::ALIGNMENT, std::mem::align_of::<T>());
+        Layout::from_size_align(count * std::mem::size_of::<T>(), align)
+            .expect("invalid AlignedVec layout")
+    }
+
     pub fn as_slice(&self) -> &[T] {
         unsafe { from_raw_parts(self.data, self.n) }
     }
@@ -57,43 +132,181 @@ impl<T> AlignedVec<T> {
     pub fn as_slice_mut(&mut self) -> &mut [T] {
         unsafe { from_raw_parts_mut(self.data, self.n) }
     }
+
+    /// Check whether the backing allocation is aligned to `align` bytes.
+    ///
+    /// Complements [`alignment_of`], which reports FFTW's SIMD alignment class,
+    /// by answering the concrete question "is this buffer aligned to `align`?".
+    pub fn is_aligned_to(&self, align: usize) -> bool {
+        align == 0 || self.data as usize % align == 0
+    }
+
+    /// View the buffer as raw bytes aliasing the same allocation.
+    ///
+    /// Useful for fast I/O and hashing of transform buffers.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { from_raw_parts(self.data as *const u8, self.n * std::mem::size_of::<T>()) }
+    }
+
+    /// Mutable byte view aliasing the same allocation as [`as_bytes`].
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { from_raw_parts_mut(self.data as *mut u8, self.n * std::mem::size_of::<T>()) }
+    }
+}
+
+impl<A: Allocator> AlignedVec<c64, A> {
+    /// View a complex buffer as `2 * len` real `f64` values.
+    ///
+    /// `c64` is `#[repr(C)]` as `{ re, im }`, so `n` complex numbers lay out
+    /// contiguously as `2 * n` `f64`; the returned slice aliases the same
+    /// allocation. This is the layout FFTW's real-to-complex plans expect.
+    pub fn as_real_slice(&self) -> &[f64] {
+        unsafe { from_raw_parts(self.data as *const f64, self.n * 2) }
+    }
+
+    /// Mutable counterpart of [`as_real_slice`]; aliases the same allocation.
+    pub fn as_real_slice_mut(&mut self) -> &mut [f64] {
+        unsafe { from_raw_parts_mut(self.data as *mut f64, self.n * 2) }
+    }
 }
 
-impl<T> Deref for AlignedVec<T> {
+impl<A: Allocator> AlignedVec<c32, A> {
+    /// View a complex buffer as `2 * len` real `f32` values.
+    ///
+    /// `c32` is `#[repr(C)]` as `{ re, im }`, so `n` complex numbers lay out
+    /// contiguously as `2 * n` `f32`; the returned slice aliases the same
+    /// allocation.
+    pub fn as_real_slice(&self) -> &[f32] {
+        unsafe { from_raw_parts(self.data as *const f32, self.n * 2) }
+    }
+
+    /// Mutable counterpart of [`as_real_slice`]; aliases the same allocation.
+    pub fn as_real_slice_mut(&mut self) -> &mut [f32] {
+        unsafe { from_raw_parts_mut(self.data as *mut f32, self.n * 2) }
+    }
+}
+
+impl<T, A: Allocator> Deref for AlignedVec<T, A> {
     type Target = [T];
     fn deref(&self) -> &[T] {
         self.as_slice()
     }
 }
 
-impl<T> DerefMut for AlignedVec<T> {
+impl<T, A: Allocator> DerefMut for AlignedVec<T, A> {
     fn deref_mut(&mut self) -> &mut [T] {
         self.as_slice_mut()
     }
 }
 
-impl<T> AlignedVec<T>
+impl<T, A: Allocator> AlignedVec<T, A>
 where
     T: AlignedAllocable,
 {
-    /// Create array with `fftw_malloc` (`fftw_free` will be automatically called by `Drop` trait)
+    /// Allocate `count` elements through `A`, returning a suitably aligned
+    /// (possibly dangling, for `count == 0`) pointer.
+    fn alloc(count: usize) -> *mut T {
+        let layout = Self::layout(count);
+        if layout.size() == 0 {
+            layout.align() as *mut T
+        } else {
+            let ptr = unsafe { A::allocate(layout) } as *mut T;
+            assert!(!ptr.is_null(), "AlignedVec allocation failed");
+            ptr
+        }
+    }
+
+    /// Create array (freed automatically by the `Drop` trait)
     pub fn new(n: usize) -> Self {
-        let ptr = excall! { T::alloc(n) };
-        let mut vec = AlignedVec { n, data: ptr };
+        let ptr = Self::alloc(n);
+        let mut vec = AlignedVec {
+            n,
+            cap: n,
+            data: ptr,
+            alloc: PhantomData,
+        };
         for v in vec.iter_mut() {
             *v = T::zero();
         }
         vec
     }
+
+    /// Create an empty array with room for at least `cap` elements.
+    ///
+    /// No element is initialized; use [`push`](Self::push) or
+    /// [`resize`](Self::resize) to populate it.
+    pub fn with_capacity(cap: usize) -> Self {
+        let ptr = Self::alloc(cap);
+        AlignedVec {
+            n: 0,
+            cap,
+            data: ptr,
+            alloc: PhantomData,
+        }
+    }
+
+    /// Amortized target capacity: at least `required`, at least double the
+    /// current capacity, rounded up to a power of two so repeated growth stays
+    /// SIMD-friendly.
+    fn amortized_capacity(&self, required: usize) -> usize {
+        let want = std::cmp::max(required, self.cap * 2);
+        let max_rem = want.next_power_of_two() - 1;
+        (want + max_rem) & !max_rem
+    }
+
+    /// Ensure space for at least `additional` more elements.
+    ///
+    /// On growth a fresh aligned block is allocated through the backend `A` (so
+    /// its alignment guarantee is re-established — a plain `realloc` would not),
+    /// the initialized prefix is copied over, and the old allocation is freed.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.n + additional;
+        if required <= self.cap {
+            return;
+        }
+        let new_cap = self.amortized_capacity(required);
+        let new_ptr = Self::alloc(new_cap);
+        let dst = unsafe { from_raw_parts_mut(new_ptr, self.n) };
+        dst.copy_from_slice(self.as_slice());
+        let old_layout = Self::layout(self.cap);
+        if old_layout.size() != 0 {
+            unsafe { A::deallocate(self.data as *mut u8, old_layout) };
+        }
+        self.data = new_ptr;
+        self.cap = new_cap;
+    }
+
+    /// Append an element, growing the aligned buffer if necessary.
+    pub fn push(&mut self, value: T) {
+        if self.n == self.cap {
+            self.reserve(1);
+        }
+        unsafe { *self.data.add(self.n) = value };
+        self.n += 1;
+    }
+
+    /// Resize to `new_len`, filling any new slots with `value`.
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len > self.n {
+            self.reserve(new_len - self.n);
+            for i in self.n..new_len {
+                unsafe { *self.data.add(i) = value };
+            }
+        }
+        self.n = new_len;
+    }
 }
 
-impl<T> Drop for AlignedVec<T> {
+impl<T, A: Allocator> Drop for AlignedVec<T, A> {
     fn drop(&mut self) {
-        excall! { ffi::fftw_free(self.data as *mut c_void) };
+        let layout = Self::layout(self.cap);
+        if layout.size() != 0 {
+            unsafe { A::deallocate(self.data as *mut u8, layout) };
+        }
     }
 }
 
-impl<T> Clone for AlignedVec<T>
+impl<T, A: Allocator> Clone for AlignedVec<T, A>
 where
     T: AlignedAllocable,
 {
@@ -104,7 +317,7 @@ where
     }
 }
 
-impl<T> PartialEq for AlignedVec<T>
+impl<T, A: Allocator> PartialEq for AlignedVec<T, A>
 where
     T: PartialEq,
 {
@@ -116,8 +329,102 @@ where
     }
 }
 
-unsafe impl<T: Send> Send for AlignedVec<T> {}
-unsafe impl<T: Sync> Sync for AlignedVec<T> {}
+unsafe impl<T: Send, A: Allocator> Send for AlignedVec<T, A> {}
+unsafe impl<T: Sync, A: Allocator> Sync for AlignedVec<T, A> {}
+
+/// A RAII-wrapper of `std::alloc` with an explicit, possibly over-aligned
+/// backing store.
+///
+/// Unlike [`AlignedVec`], which by default inherits FFTW's [SIMD alignment] from
+/// `fftw_malloc` (via [`FftwAlloc`]), `OverAlignedVec` allocates through the global allocator and
+/// lets callers request an alignment that *exceeds* it — for instance 64 bytes
+/// when targeting AVX-512. `ALIGN` is checked at construction to be a power of
+/// two and at least `align_of::<T>()`.
+///
+/// [SIMD alignment]: http://www.fftw.org/fftw3_doc/SIMD-alignment-and-fftw_005fmalloc.html
+#[derive(Debug)]
+pub struct OverAlignedVec<T, const ALIGN: usize> {
+    n: usize,
+    data: *mut T,
+}
+
+impl<T, const ALIGN: usize> OverAlignedVec<T, ALIGN> {
+    /// `Layout` of the backing store for `n` elements.
+    ///
+    /// The size is rounded up to a multiple of `ALIGN` so that the whole
+    /// allocation is covered by aligned SIMD loads/stores.
+    fn layout(n: usize) -> Layout {
+        let size = (n * std::mem::size_of::<T>() + (ALIGN - 1)) & !(ALIGN - 1);
+        Layout::from_size_align(size, ALIGN).expect("invalid over-aligned layout")
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { from_raw_parts(self.data, self.n) }
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        unsafe { from_raw_parts_mut(self.data, self.n) }
+    }
+
+    /// Check whether the backing allocation is aligned to `align` bytes.
+    pub fn is_aligned_to(&self, align: usize) -> bool {
+        align == 0 || self.data as usize % align == 0
+    }
+}
+
+impl<T, const ALIGN: usize> OverAlignedVec<T, ALIGN>
+where
+    T: Zero + Copy,
+{
+    /// Create a zero-initialized array over-aligned to `ALIGN` bytes.
+    pub fn new(n: usize) -> Self {
+        assert!(ALIGN.is_power_of_two(), "ALIGN must be a power of two");
+        assert!(
+            ALIGN >= std::mem::align_of::<T>(),
+            "ALIGN must be at least align_of::<T>()"
+        );
+        let layout = Self::layout(n);
+        // A zero-sized layout cannot be passed to `alloc`; hand out a dangling
+        // but suitably aligned pointer instead (never dereferenced for n == 0).
+        let data = if layout.size() == 0 {
+            ALIGN as *mut T
+        } else {
+            let ptr = unsafe { alloc(layout) } as *mut T;
+            assert!(!ptr.is_null(), "over-aligned allocation failed");
+            ptr
+        };
+        let mut vec = OverAlignedVec { n, data };
+        for v in vec.iter_mut() {
+            *v = T::zero();
+        }
+        vec
+    }
+}
+
+impl<T, const ALIGN: usize> Deref for OverAlignedVec<T, ALIGN> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const ALIGN: usize> DerefMut for OverAlignedVec<T, ALIGN> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_slice_mut()
+    }
+}
+
+impl<T, const ALIGN: usize> Drop for OverAlignedVec<T, ALIGN> {
+    fn drop(&mut self) {
+        let layout = Self::layout(self.n);
+        if layout.size() != 0 {
+            unsafe { dealloc(self.data as *mut u8, layout) };
+        }
+    }
+}
+
+unsafe impl<T: Send, const ALIGN: usize> Send for OverAlignedVec<T, ALIGN> {}
+unsafe impl<T: Sync, const ALIGN: usize> Sync for OverAlignedVec<T, ALIGN> {}
 
 pub type Alignment = i32;
 
@@ -132,79 +439,248 @@ pub fn alignment_of<T>(a: &[T]) -> Alignment {
     unsafe { ffi::fftw_alignment_of(a.as_ptr() as *mut _) }
 }
 
+#[cfg(test)]
+mod test {
+    use super::{AlignedVec, OverAlignedVec};
+    use crate::types::c64;
+
+    #[test]
+    fn push_past_capacity_grows() {
+        let mut v: AlignedVec<f64> = AlignedVec::with_capacity(2);
+        assert_eq!(v.len(), 0);
+        for i in 0..5 {
+            v.push(i as f64);
+        }
+        assert_eq!(v.len(), 5);
+        assert_eq!(v.as_slice(), &[0.0, 1.0, 2.0, 3.0, 4.0][..]);
+    }
+
+    #[test]
+    fn resize_grow_then_shrink() {
+        let mut v: AlignedVec<f64> = AlignedVec::new(2);
+        v[0] = 1.0;
+        v[1] = 2.0;
+        v.resize(4, 9.0);
+        assert_eq!(v.as_slice(), &[1.0, 2.0, 9.0, 9.0][..]);
+        v.resize(1, 0.0);
+        assert_eq!(v.as_slice(), &[1.0][..]);
+    }
+
+    #[test]
+    fn over_aligned_is_aligned_to_64() {
+        let v: OverAlignedVec<c64, 64> = OverAlignedVec::new(8);
+        assert_eq!(v.len(), 8);
+        assert!(v.is_aligned_to(64));
+        assert!(v.is_aligned_to(16));
+    }
+
+    #[test]
+    fn is_aligned_to_zero_does_not_panic() {
+        let v: AlignedVec<f64> = AlignedVec::new(1);
+        assert!(v.is_aligned_to(0));
+        let w: OverAlignedVec<f64, 32> = OverAlignedVec::new(1);
+        assert!(w.is_aligned_to(0));
+    }
+}
+
 #[cfg(feature = "serialize")]
 mod serde {
     use std::fmt;
     use std::marker::PhantomData;
 
     use serde::de::{Error, SeqAccess, Visitor};
-    use serde::ser::{Serialize, SerializeSeq, Serializer};
+    use serde::ser::{Serialize, SerializeSeq, SerializeTuple, Serializer};
     use serde::{Deserialize, Deserializer};
 
-    use crate::array::AlignedAllocable;
+    use crate::array::{AlignedAllocable, Allocator};
 
     use super::AlignedVec;
 
-    impl<T> Serialize for AlignedVec<T>
+    /// Byte-swap each real scalar in place (on-wire bytes are little-endian).
+    #[cfg(target_endian = "big")]
+    fn swap_scalars<T: AlignedAllocable>(bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(T::SCALAR_BYTES) {
+            chunk.reverse();
+        }
+    }
+
+    /// Little-endian byte image of the buffer, ready for the wire.
+    fn to_wire_bytes<T, A>(v: &AlignedVec<T, A>) -> Vec<u8>
+    where
+        T: AlignedAllocable,
+        A: Allocator,
+    {
+        let mut bytes = v.as_bytes().to_vec();
+        #[cfg(target_endian = "big")]
+        swap_scalars::<T>(&mut bytes);
+        bytes
+    }
+
+    /// Newtype forcing `serialize_bytes` for the raw compact payload.
+    struct RawBytes<'a>(&'a [u8]);
+
+    impl Serialize for RawBytes<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    /// Newtype recovering the raw payload regardless of how the format models
+    /// bytes (a `bytes` value, a borrowed buffer, or a `u8` sequence).
+    struct RawByteBuf(Vec<u8>);
+
+    impl<'de> Deserialize<'de> for RawByteBuf {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct RawVisitor;
+
+            impl<'de> Visitor<'de> for RawVisitor {
+                type Value = Vec<u8>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "raw bytes")
+                }
+
+                fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+                    Ok(v.to_vec())
+                }
+
+                fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+                    Ok(v)
+                }
+
+                fn visit_seq<S>(self, mut seq: S) -> Result<Vec<u8>, S::Error>
+                where
+                    S: SeqAccess<'de>,
+                {
+                    let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                    while let Some(b) = seq.next_element::<u8>()? {
+                        out.push(b);
+                    }
+                    Ok(out)
+                }
+            }
+
+            deserializer.deserialize_bytes(RawVisitor).map(RawByteBuf)
+        }
+    }
+
+    impl<T, A> Serialize for AlignedVec<T, A>
     where
-        T: Serialize,
+        T: Serialize + AlignedAllocable,
+        A: Allocator,
     {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            let mut seq = serializer.serialize_seq(Some(self.len()))?;
-            for e in self.iter() {
-                seq.serialize_element(e)?;
+            // Human-readable formats keep the per-element sequence; all other
+            // formats use the compact length + raw aligned bytes codec.
+            if serializer.is_human_readable() {
+                let mut seq = serializer.serialize_seq(Some(self.len()))?;
+                for e in self.iter() {
+                    seq.serialize_element(e)?;
+                }
+                seq.end()
+            } else {
+                let bytes = to_wire_bytes(self);
+                let mut tup = serializer.serialize_tuple(2)?;
+                tup.serialize_element(&self.len())?;
+                tup.serialize_element(&RawBytes(&bytes))?;
+                tup.end()
             }
-            seq.end()
         }
     }
 
-    struct AlignedVecVisitor<T>(PhantomData<T>);
+    struct AlignedVecVisitor<T, A>(PhantomData<(T, A)>);
 
-    impl<'de, T> Visitor<'de> for AlignedVecVisitor<T>
+    impl<'de, T, A> Visitor<'de> for AlignedVecVisitor<T, A>
     where
         T: AlignedAllocable + Deserialize<'de>,
+        A: Allocator,
     {
-        type Value = AlignedVec<T>;
+        type Value = AlignedVec<T, A>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
             write!(formatter, "AlignedVec<T>")
         }
 
-        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, <A as SeqAccess<'de>>::Error>
+        fn visit_seq<S>(self, seq: S) -> Result<Self::Value, <S as SeqAccess<'de>>::Error>
         where
-            A: SeqAccess<'de>,
+            S: SeqAccess<'de>,
         {
             let mut seq = seq;
-            let mut output = AlignedVec::new(seq.size_hint().ok_or(A::Error::custom(
+            let mut output = AlignedVec::new(seq.size_hint().ok_or(S::Error::custom(
                 "Failed to retrieve the size of the AlignedVec.",
             ))?);
             for val in output.iter_mut() {
                 *val = seq
                     .next_element()?
-                    .ok_or(A::Error::custom("Failed to retrieve the next element"))?
+                    .ok_or(S::Error::custom("Failed to retrieve the next element"))?
             }
             Ok(output)
         }
     }
 
-    impl<'de, T> Deserialize<'de> for AlignedVec<T>
+    struct CompactVisitor<T, A>(PhantomData<(T, A)>);
+
+    impl<'de, T, A> Visitor<'de> for CompactVisitor<T, A>
     where
         T: AlignedAllocable + Deserialize<'de>,
+        A: Allocator,
+    {
+        type Value = AlignedVec<T, A>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a (length, bytes) pair")
+        }
+
+        fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, <S as SeqAccess<'de>>::Error>
+        where
+            S: SeqAccess<'de>,
+        {
+            let n: usize = seq
+                .next_element()?
+                .ok_or_else(|| S::Error::custom("Failed to retrieve the length of the AlignedVec."))?;
+            let RawByteBuf(bytes) = seq
+                .next_element()?
+                .ok_or_else(|| S::Error::custom("Failed to retrieve the AlignedVec bytes."))?;
+            if bytes.len() != n * std::mem::size_of::<T>() {
+                return Err(S::Error::custom("AlignedVec byte length mismatch"));
+            }
+            let mut output = AlignedVec::new(n);
+            output.as_bytes_mut().copy_from_slice(&bytes);
+            #[cfg(target_endian = "big")]
+            swap_scalars::<T>(output.as_bytes_mut());
+            Ok(output)
+        }
+    }
+
+    impl<'de, T, A> Deserialize<'de> for AlignedVec<T, A>
+    where
+        T: AlignedAllocable + Deserialize<'de>,
+        A: Allocator,
     {
         fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_seq(AlignedVecVisitor(PhantomData))
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_seq(AlignedVecVisitor(PhantomData))
+            } else {
+                deserializer.deserialize_tuple(2, CompactVisitor(PhantomData))
+            }
         }
     }
 
     #[cfg(test)]
     mod test {
-        use serde_test::{assert_tokens, Token};
+        use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Token};
 
         use crate::types::{c32, c64};
 
@@ -217,6 +693,76 @@ mod serde {
             assert_tokens(&vec, &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
         }
 
+        #[test]
+        fn test_ser_de_compact_c32() {
+            let mut vec = AlignedVec::new(3);
+            vec[0] = c32::new(1., 2.);
+            vec[1] = c32::new(3., 4.);
+            vec[2] = c32::new(5., 6.);
+
+            // Little-endian IEEE-754 image of [1, 2, 3, 4, 5, 6] as f32.
+            assert_tokens(
+                &vec.compact(),
+                &[
+                    Token::Tuple { len: 2 },
+                    Token::U64(3),
+                    Token::Bytes(&[
+                        0x00, 0x00, 0x80, 0x3f, // 1.0
+                        0x00, 0x00, 0x00, 0x40, // 2.0
+                        0x00, 0x00, 0x40, 0x40, // 3.0
+                        0x00, 0x00, 0x80, 0x40, // 4.0
+                        0x00, 0x00, 0xa0, 0x40, // 5.0
+                        0x00, 0x00, 0xc0, 0x40, // 6.0
+                    ]),
+                    Token::TupleEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_compact_length_mismatch() {
+            // Declares 3 elements but only supplies 4 bytes (one f32).
+            assert_de_tokens_error::<Compact<AlignedVec<c32>>>(
+                &[
+                    Token::Tuple { len: 2 },
+                    Token::U64(3),
+                    Token::Bytes(&[0x00, 0x00, 0x80, 0x3f]),
+                    Token::TupleEnd,
+                ],
+                "AlignedVec byte length mismatch",
+            );
+        }
+
+        #[test]
+        fn test_ser_de_compact_empty_c32() {
+            let vec: AlignedVec<c32> = AlignedVec::new(0);
+
+            assert_tokens(
+                &vec.compact(),
+                &[
+                    Token::Tuple { len: 2 },
+                    Token::U64(0),
+                    Token::Bytes(&[]),
+                    Token::TupleEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn test_ser_de_compact_empty_c64() {
+            let vec: AlignedVec<c64> = AlignedVec::new(0);
+
+            assert_tokens(
+                &vec.compact(),
+                &[
+                    Token::Tuple { len: 2 },
+                    Token::U64(0),
+                    Token::Bytes(&[]),
+                    Token::TupleEnd,
+                ],
+            );
+        }
+
         #[test]
         fn test_ser_de_empty_c64() {
             let vec: AlignedVec<c64> = AlignedVec::new(0);